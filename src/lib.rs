@@ -21,4 +21,4 @@ mod json;
 mod parser;
 mod seeker;
 
-pub use seeker::{DocItem, RustDoc, RustDocSeeker, TypeItem};
+pub use seeker::{DocItem, RustDoc, RustDocSeeker, SearchType, TypeItem};
@@ -1,7 +1,8 @@
 use crate::{
     json::fix_json,
-    seeker::{DocItem, RustDoc, TypeItem},
+    seeker::{DocItem, RustDoc, SearchType, TypeItem},
 };
+use itertools::izip;
 use serde::Deserialize;
 use serde_json::{self, Value};
 use std::{collections::BTreeSet, str::FromStr};
@@ -23,6 +24,8 @@ struct IndexItem {
     parent: Option<Parent>,
     parent_idx: Option<usize>,
     search_type: Option<Value>,
+    #[serde(skip_deserializing)]
+    krate: Atom,
 }
 
 #[derive(Debug, Deserialize)]
@@ -34,13 +37,117 @@ struct SearchIndex {
     paths: Vec<Parent>,
 }
 
+/// The columnar `search-index.js` layout rustdoc switched to around Rust
+/// 1.44: instead of one object per item, each field is a parallel array.
+#[derive(Debug, Deserialize)]
+struct ColumnarIndex {
+    doc: Atom,
+    /// One char per item, encoding its `TypeItem` tag in base 36.
+    #[serde(rename = "t")]
+    types: String,
+    #[serde(rename = "n")]
+    names: Vec<Atom>,
+    /// Sparse `[index, path]` overrides; a path applies until the next one.
+    #[serde(rename = "q")]
+    path_overrides: Vec<(usize, Atom)>,
+    #[serde(rename = "d")]
+    descs: Vec<Atom>,
+    /// One entry per item: 0 means no parent, otherwise a 1-based index
+    /// into `paths`.
+    #[serde(rename = "i")]
+    parent_idx: Vec<usize>,
+    #[serde(rename = "p")]
+    paths: Vec<(usize, Atom)>,
+    #[serde(rename = "f")]
+    search_types: Option<Vec<Option<Value>>>,
+}
+
 impl From<IndexItem> for DocItem {
     /// Convert an IndexItem to DocItem based on if parent exists.
     fn from(item: IndexItem) -> DocItem {
         let name = TypeItem::new(item.ty, item.name);
+        let is_method = matches!(item.ty, 10 | 11);
         let parent = item.parent.map(|x| TypeItem::new(x.ty, x.name));
 
-        DocItem::new(name, parent, item.path, item.desc)
+        let search_type = item
+            .search_type
+            .as_ref()
+            .and_then(SearchType::from_value)
+            .map(|search_type| {
+                if is_method {
+                    search_type.with_implicit_self()
+                } else {
+                    search_type
+                }
+            });
+
+        DocItem::new(name, parent, item.path, item.desc, search_type, item.krate)
+    }
+}
+
+impl ColumnarIndex {
+    /// Reconstruct `DocItem`s from the parallel column arrays.
+    fn into_doc_items(self) -> Vec<DocItem> {
+        let krate = self.doc;
+
+        let parents: Vec<Parent> = self
+            .paths
+            .into_iter()
+            .map(|(ty, name)| Parent { ty, name })
+            .collect();
+
+        let mut paths = vec![Atom::from(""); self.names.len()];
+        let mut last_path = Atom::from("");
+        let mut overrides = self.path_overrides.into_iter().peekable();
+        for (i, path) in paths.iter_mut().enumerate() {
+            while overrides.peek().is_some_and(|(idx, _)| *idx == i) {
+                last_path = overrides.next().unwrap().1;
+            }
+            *path = last_path.clone();
+        }
+
+        let mut search_types = self
+            .search_types
+            .unwrap_or_default()
+            .into_iter()
+            .chain(std::iter::repeat(None));
+
+        izip!(
+            self.types.chars(),
+            self.names,
+            self.descs,
+            paths,
+            self.parent_idx
+        )
+        .map(|(ty, name, desc, path, parent_idx)| {
+            let ty = ty.to_digit(36).expect("invalid type tag") as usize;
+            let is_method = matches!(ty, 10 | 11);
+            let name = TypeItem::new(ty, name);
+
+            let parent = match parent_idx {
+                0 => None,
+                idx => {
+                    let parent = parents[idx - 1].clone();
+                    Some(TypeItem::new(parent.ty, parent.name))
+                }
+            };
+
+            let search_type = search_types
+                .next()
+                .flatten()
+                .as_ref()
+                .and_then(SearchType::from_value)
+                .map(|search_type| {
+                    if is_method {
+                        search_type.with_implicit_self()
+                    } else {
+                        search_type
+                    }
+                });
+
+            DocItem::new(name, parent, path, desc, search_type, krate.clone())
+        })
+        .collect()
     }
 }
 
@@ -55,8 +162,17 @@ impl FromStr for RustDoc {
             let line = line.split_at(eq).1.trim().trim_end_matches(';');
 
             let json = fix_json(line);
+            let value: Value = serde_json::from_str(&json).unwrap();
+
+            // The columnar format (Rust 1.44+) has a `n` array of names;
+            // the legacy format has a flat `i` array of item objects.
+            if value.get("n").is_some() {
+                let index: ColumnarIndex = serde_json::from_value(value).unwrap();
+                items.extend(index.into_doc_items());
+                continue;
+            }
 
-            let index: SearchIndex = serde_json::from_str(&json).unwrap();
+            let index: SearchIndex = serde_json::from_value(value).unwrap();
 
             let mut last_path = Atom::from("");
             let parents = index.paths;
@@ -72,6 +188,7 @@ impl FromStr for RustDoc {
 
                 // parent_idx is the index of the item in SearchIndex.paths
                 item.parent = item.parent_idx.map(|idx| parents[idx].clone());
+                item.krate = index.doc.clone();
 
                 items.insert(DocItem::from(item));
             }
@@ -91,4 +208,28 @@ mod test {
         let data = fs::read_to_string("search-index.js").unwrap();
         let _: RustDoc = data.parse().unwrap();
     }
+
+    #[test]
+    fn test_parse_columnar_format() {
+        let line = r#"searchIndex["demo"] = {"doc":"demo","t":"3b","n":["Foo","new"],"q":[[0,"demo"]],"d":["A struct.","Construct a Foo."],"i":[0,1],"p":[[3,"Foo"]]};"#;
+
+        let rustdoc: RustDoc = line.parse().unwrap();
+        let seeker = rustdoc.build();
+
+        assert_eq!(seeker.crates().collect::<Vec<_>>(), vec!["demo"]);
+
+        let items: Vec<_> = seeker
+            .search(&fst::automaton::Subsequence::new("Foo"))
+            .collect();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path.as_ref(), "demo");
+        assert!(items[0].parent.is_none());
+
+        let items: Vec<_> = seeker
+            .search(&fst::automaton::Subsequence::new("new"))
+            .collect();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path.as_ref(), "demo");
+        assert_eq!(items[0].parent, Some(TypeItem::Struct(Atom::from("Foo"))));
+    }
 }
@@ -1,14 +1,18 @@
-use fst::{Automaton, IntoStreamer, Map, MapBuilder};
+use fst::{automaton::Subsequence, Automaton, IntoStreamer, Map, MapBuilder};
 use itertools::Itertools;
 use std::{
     cmp::{Ord, Ordering},
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     fmt,
     iter::FromIterator,
     u32,
 };
 use string_cache::DefaultAtom as Atom;
 
+/// A wildcard atom, used in place of a generic or otherwise unresolved type
+/// so that it matches any query type during signature search.
+const WILDCARD: &str = "_";
+
 macro_rules! enum_number {
     ($name:ident { $($variant:ident | $display:tt | $value:tt, )* }) => {
         /// TypeItem represent an item with type,
@@ -83,6 +87,114 @@ enum_number!(TypeItem {
     Existential     | "existential"     | 22,
 });
 
+/// SearchType represents the normalized, type-driven signature of a function
+/// or method, parsed from rustdoc's `search_type` index payload.
+///
+/// Type names are lowercased and generic parameters are collapsed to their
+/// outer type, so `Vec<T>` becomes the atom `vec`. A type node with no name
+/// (or one that is neither an object nor a list) is normalized to the
+/// wildcard atom, which matches any query type in signature search. A named
+/// generic parameter such as `T` is not told apart from a concrete type: it
+/// keeps its lowercased name (`t`) and matches only that name.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SearchType {
+    pub inputs: Vec<Atom>,
+    pub output: Vec<Atom>,
+}
+
+impl SearchType {
+    /// Parse a rustdoc `search_type` payload of the form
+    /// `{"i": [inputs], "o": output}`, where each type node is either
+    /// `{"name": "<type>"}` or a nested generic list.
+    pub(crate) fn from_value(value: &serde_json::Value) -> Option<SearchType> {
+        let obj = value.as_object()?;
+
+        let inputs = obj
+            .get("i")
+            .and_then(serde_json::Value::as_array)
+            .map(|nodes| nodes.iter().filter_map(Self::normalize_node).collect())
+            .unwrap_or_default();
+
+        let output = obj
+            .get("o")
+            .and_then(Self::normalize_node)
+            .into_iter()
+            .collect();
+
+        Some(SearchType { inputs, output })
+    }
+
+    /// Normalize a single type node, or `None` for an explicit `null`
+    /// (a unit-returning function's output, for instance) — distinct from
+    /// the wildcard atom, which stands for an unresolved/generic type and
+    /// matches any query type.
+    fn normalize_node(node: &serde_json::Value) -> Option<Atom> {
+        match node {
+            serde_json::Value::Null => None,
+            serde_json::Value::Object(map) => Some(
+                map.get("name")
+                    .and_then(serde_json::Value::as_str)
+                    .map(|name| Atom::from(name.to_lowercase()))
+                    .unwrap_or_else(|| Atom::from(WILDCARD)),
+            ),
+            // a nested generic list: collapse to its outer type.
+            serde_json::Value::Array(nodes) => Some(
+                nodes
+                    .first()
+                    .and_then(Self::normalize_node)
+                    .unwrap_or_else(|| Atom::from(WILDCARD)),
+            ),
+            _ => Some(Atom::from(WILDCARD)),
+        }
+    }
+
+    /// Prepend the implicit `self` input, for methods and trait methods.
+    pub(crate) fn with_implicit_self(mut self) -> SearchType {
+        self.inputs.insert(0, Atom::from("self"));
+        self
+    }
+
+    /// Canonical signature key, e.g. `"vec,usize->bool"`, used to index and
+    /// group items that share the same normalized signature.
+    fn canonical_key(&self) -> Atom {
+        let inputs = self.inputs.iter().map(AsRef::as_ref).join(",");
+        let output = self.output.iter().map(AsRef::as_ref).join(",");
+        Atom::from(format!("{}->{}", inputs, output))
+    }
+
+    /// Whether a signature key matches a query: every query input must be
+    /// present among the stored inputs (a superset match), and the stored
+    /// output must match the query output when one is given. The wildcard
+    /// atom matches anything on either side.
+    fn key_matches(key: &str, inputs: &[&str], output: Option<&str>) -> bool {
+        let (stored_inputs, stored_output) = key.split_at(key.find("->").unwrap_or(key.len()));
+        let stored_output = stored_output.trim_start_matches("->");
+
+        let inputs_ok = inputs.iter().all(|query| {
+            let query = query.to_lowercase();
+            stored_inputs
+                .split(',')
+                .any(|atom| atom == WILDCARD || atom == query)
+        });
+
+        let output_ok = match output {
+            None => true,
+            Some(query) => {
+                let query = query.to_lowercase();
+                // An empty stored output means the item has no return type
+                // (a unit-returning function, for instance) and so can only
+                // match an unspecified-output query, not a concrete one.
+                !stored_output.is_empty()
+                    && stored_output
+                        .split(',')
+                        .any(|atom| atom == WILDCARD || atom == query)
+            }
+        };
+
+        inputs_ok && output_ok
+    }
+}
+
 /// DocItem represent a searchable item,
 /// use `Display` to get the relative URI of the item.
 ///
@@ -97,6 +209,8 @@ enum_number!(TypeItem {
 /// #     parent: Some(TypeItem::Struct(Atom::from("Vec"))),
 /// #     path: Atom::from("std::vec"),
 /// #     desc: Atom::from(""),
+/// #     search_type: None,
+/// #     krate: Atom::from("std"),
 /// # };
 /// // `vec_dedup` is the `DocItem` for `std::vec::Vec::dedup`.
 /// assert_eq!(format!("{}", vec_dedup), "std/vec/struct.Vec.html#fn.dedup");
@@ -106,6 +220,8 @@ enum_number!(TypeItem {
 /// #     parent: None,
 /// #     path: Atom::from("std::vec"),
 /// #     desc: Atom::from(""),
+/// #     search_type: None,
+/// #     krate: Atom::from("std"),
 /// # };
 /// // `vec_struct` is the `DocItem` for `std::vec::Vec`.
 /// assert_eq!(format!("{}", vec_struct), "std/vec/struct.Vec.html");
@@ -115,6 +231,8 @@ enum_number!(TypeItem {
 /// #     parent: None,
 /// #     path: Atom::from("std"),
 /// #     desc: Atom::from(""),
+/// #     search_type: None,
+/// #     krate: Atom::from("std"),
 /// # };
 /// // `vec_macro` is the `DocItem` for `std::vec` macro.
 /// assert_eq!(format!("{}", vec_macro), "std/macro.vec.html");
@@ -125,15 +243,28 @@ pub struct DocItem {
     pub parent: Option<TypeItem>,
     pub path: Atom,
     pub desc: Atom,
+    /// Normalized type signature, for `RustDocSeeker::search_by_type`.
+    pub search_type: Option<SearchType>,
+    /// Name of the crate this item was indexed from.
+    pub krate: Atom,
 }
 
 impl DocItem {
-    pub fn new(name: TypeItem, parent: Option<TypeItem>, path: Atom, desc: Atom) -> DocItem {
+    pub fn new(
+        name: TypeItem,
+        parent: Option<TypeItem>,
+        path: Atom,
+        desc: Atom,
+        search_type: Option<SearchType>,
+        krate: Atom,
+    ) -> DocItem {
         DocItem {
             name,
             parent,
             path,
             desc,
+            search_type,
+            krate,
         }
     }
 
@@ -195,6 +326,51 @@ impl fmt::Display for DocItem {
     }
 }
 
+/// Lower is more relevant. Compared lexicographically: exact name match,
+/// then prefix match, then edit distance to the query, then shallower
+/// path depth, then item-type priority.
+type Rank = (u8, u8, usize, usize, u8);
+
+fn rank(item: &DocItem, query: &str) -> Rank {
+    let name = item.name.as_ref().to_lowercase();
+
+    let exact = if name == query { 0 } else { 1 };
+    let prefix = if name.starts_with(query) { 0 } else { 1 };
+    let distance = levenshtein_distance(&name, query);
+    let depth = item.path.split("::").count();
+    let type_priority = match &item.name {
+        TypeItem::Struct(_) | TypeItem::Function(_) | TypeItem::Trait(_) => 0,
+        TypeItem::Enum(_) | TypeItem::Module(_) | TypeItem::Macro(_) => 1,
+        TypeItem::Method(_) | TypeItem::TyMethod(_) | TypeItem::AssociatedType(_) => 2,
+        TypeItem::Impl(_) | TypeItem::StructField(_) => 4,
+        _ => 3,
+    };
+
+    (exact, prefix, distance, depth, type_priority)
+}
+
+/// Classic Levenshtein edit distance between two strings.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev_diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            let cost = if ca == cb { 0 } else { 1 };
+            let new_value = (row[j] + 1).min(above + 1).min(prev_diagonal + cost);
+
+            prev_diagonal = above;
+            row[j + 1] = new_value;
+        }
+    }
+
+    row[b.len()]
+}
+
 /// RustDoc contains DocItems, which could be convert to RustDocSeeker.
 ///
 /// # Example
@@ -266,10 +442,94 @@ impl RustDoc {
         }
 
         let index = builder.into_map();
-        RustDocSeeker { items, index }
+
+        let mut type_index: BTreeMap<Atom, Vec<u32>> = BTreeMap::new();
+        for (i, item) in items.iter().enumerate() {
+            if let Some(ref search_type) = item.search_type {
+                type_index
+                    .entry(search_type.canonical_key())
+                    .or_default()
+                    .push(i as u32);
+            }
+        }
+
+        let (desc_index, desc_postings) = {
+            let mut terms: Vec<(Atom, u32)> = items
+                .iter()
+                .enumerate()
+                .flat_map(|(i, item)| {
+                    tokenize(item.desc.as_ref()).map(move |term| (term, i as u32))
+                })
+                .collect();
+            terms.sort();
+            terms.dedup();
+
+            let mut builder = MapBuilder::memory();
+            let mut postings = Vec::with_capacity(terms.len());
+
+            let groups = terms.iter().group_by(|(term, _)| term.clone());
+            for (term, group) in groups.into_iter() {
+                let start = postings.len() as u32;
+                postings.extend(group.map(|(_, idx)| *idx));
+                let end = postings.len() as u32;
+                let val = ((start as u64) << 32) + end as u64;
+                builder.insert(term.as_bytes(), val).unwrap();
+            }
+
+            (builder.into_map(), postings.into_boxed_slice())
+        };
+
+        let (name_ci_index, name_ci_postings) = {
+            let mut terms: Vec<(Atom, u32)> = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (Atom::from(item.name.as_ref().to_lowercase()), i as u32))
+                .collect();
+            terms.sort();
+
+            let mut builder = MapBuilder::memory();
+            let mut postings = Vec::with_capacity(terms.len());
+
+            let groups = terms.iter().group_by(|(term, _)| term.clone());
+            for (term, group) in groups.into_iter() {
+                let start = postings.len() as u32;
+                postings.extend(group.map(|(_, idx)| *idx));
+                let end = postings.len() as u32;
+                let val = ((start as u64) << 32) + end as u64;
+                builder.insert(term.as_bytes(), val).unwrap();
+            }
+
+            (builder.into_map(), postings.into_boxed_slice())
+        };
+
+        let crates = {
+            let mut crates: Vec<Atom> = items.iter().map(|item| item.krate.clone()).collect();
+            crates.sort();
+            crates.dedup();
+            crates.into_boxed_slice()
+        };
+
+        RustDocSeeker {
+            items,
+            index,
+            type_index,
+            desc_index,
+            desc_postings,
+            name_ci_index,
+            name_ci_postings,
+            crates,
+        }
     }
 }
 
+/// Split description text into lowercased word terms for the description
+/// index.
+fn tokenize(desc: &str) -> impl Iterator<Item = Atom> + '_ {
+    desc.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| Atom::from(term.to_lowercase()))
+}
+
 /// RustDocSeeker contains DocItems and Index for fast searching.
 ///
 /// The index is kv-map for <name, idx: u64 = (start: u32 << 32) + end: u32>
@@ -287,6 +547,19 @@ impl RustDoc {
 pub struct RustDocSeeker {
     items: Box<[DocItem]>,
     index: Map<Vec<u8>>,
+    type_index: BTreeMap<Atom, Vec<u32>>,
+    /// kv-map for <term, idx: u64 = (start << 32) + end> where
+    /// `desc_postings[start..end]` holds the indices of items whose `desc`
+    /// contains `term`.
+    desc_index: Map<Vec<u8>>,
+    desc_postings: Box<[u32]>,
+    /// kv-map for <lowercased name, idx: u64 = (start << 32) + end> where
+    /// `name_ci_postings[start..end]` holds the indices of items with that
+    /// lowercased name; used for case-insensitive name search.
+    name_ci_index: Map<Vec<u8>>,
+    name_ci_postings: Box<[u32]>,
+    /// Sorted, deduped names of the crates indexed here.
+    crates: Box<[Atom]>,
 }
 
 impl RustDocSeeker {
@@ -349,4 +622,328 @@ impl RustDocSeeker {
             &self.items[start..end]
         })
     }
+
+    /// Search by function/method signature, in the spirit of rustdoc's own
+    /// "search by type" feature. `inputs` is matched as a subset of an
+    /// item's normalized input types (so extra inputs on the item are fine),
+    /// and `output`, when given, must match the item's output type. A
+    /// wildcard atom among the item's inputs or output (see `SearchType`)
+    /// matches any query type; query types are matched by name.
+    ///
+    /// Methods carry an implicit leading `self` input, so searching for
+    /// `&["self"]` with no output matches any method that has a signature.
+    /// Items without a signature are never returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("search-index.js")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let results = seeker.search_by_type(&["self"], Some("bool"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_by_type(&self, inputs: &[&str], output: Option<&str>) -> Vec<&DocItem> {
+        self.type_index
+            .iter()
+            .filter(|(key, _)| SearchType::key_matches(key, inputs, output))
+            .flat_map(|(_, idxs)| idxs.iter().map(|&i| &self.items[i as usize]))
+            .collect()
+    }
+
+    /// Search item names case-insensitively, via `name_ci_index`. Shared by
+    /// `search_ranked` and `search_all`, which each pass in an automaton
+    /// built from the lowercased query.
+    fn search_name_ci<'a, A: Automaton>(&'a self, aut: &A) -> impl Iterator<Item = &'a DocItem> {
+        let result = self.name_ci_index.search(aut).into_stream().into_values();
+
+        result.into_iter().flat_map(move |idx| {
+            let start = (idx >> 32) as usize;
+            let end = (idx & 0xffffffff) as usize;
+            self.name_ci_postings[start..end]
+                .iter()
+                .map(move |&i| &self.items[i as usize])
+        })
+    }
+
+    /// Search by subsequence, case-insensitively, and sort the results by
+    /// relevance: exact name matches first, then prefix matches, then by
+    /// edit distance to the query, shallower paths, and preferred item
+    /// types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("search-index.js")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let results = seeker.search_ranked("vec");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_ranked(&self, query: &str) -> Vec<&DocItem> {
+        let query = query.to_lowercase();
+        let aut = Subsequence::new(&query);
+
+        let mut results: Vec<&DocItem> = self.search_name_ci(&aut).collect();
+        results.sort_by_key(|item| rank(item, &query));
+        results
+    }
+
+    /// Find items whose `desc` contains the given word.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("search-index.js")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let results = seeker.search_desc("duplicate");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_desc(&self, term: &str) -> impl Iterator<Item = &DocItem> {
+        let term = term.to_lowercase();
+
+        self.desc_index
+            .get(term.as_bytes())
+            .into_iter()
+            .flat_map(move |idx| {
+                let start = (idx >> 32) as usize;
+                let end = (idx & 0xffffffff) as usize;
+                self.desc_postings[start..end]
+                    .iter()
+                    .map(move |&i| &self.items[i as usize])
+            })
+    }
+
+    /// Search both names (by case-insensitive subsequence) and description
+    /// words, unioning the results with name matches first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("search-index.js")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let results = seeker.search_all("dedup");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_all(&self, query: &str) -> Vec<&DocItem> {
+        // As in `search_ranked`, name matching goes through `name_ci_index`
+        // so a query in any case finds names in any case; `search_desc`
+        // already lowercases both sides for the description half.
+        let lower = query.to_lowercase();
+        let aut = Subsequence::new(&lower);
+
+        let mut seen = BTreeSet::new();
+        let mut results = Vec::new();
+
+        for item in self.search_name_ci(&aut).chain(
+            query
+                .split_whitespace()
+                .flat_map(|word| self.search_desc(word)),
+        ) {
+            if seen.insert(item as *const DocItem) {
+                results.push(item);
+            }
+        }
+
+        results
+    }
+
+    /// Enumerate the distinct crates indexed here.
+    pub fn crates(&self) -> impl Iterator<Item = &str> {
+        self.crates.iter().map(AsRef::as_ref)
+    }
+
+    /// Search with `fst::Automaton`, restricted to a single crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("search-index.js")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// let results = seeker.search_in("std", &aut);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_in<'a, A: Automaton>(
+        &'a self,
+        krate: &'a str,
+        aut: &A,
+    ) -> impl Iterator<Item = &'a DocItem> {
+        self.search(aut)
+            .filter(move |item| item.krate.as_ref() == krate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn item(
+        name: TypeItem,
+        parent: Option<TypeItem>,
+        path: &str,
+        desc: &str,
+        search_type: Option<SearchType>,
+        krate: &str,
+    ) -> DocItem {
+        DocItem::new(
+            name,
+            parent,
+            Atom::from(path),
+            Atom::from(desc),
+            search_type,
+            Atom::from(krate),
+        )
+    }
+
+    #[test]
+    fn test_search_type_from_value_normalizes_names() {
+        let value = json!({"i": [{"name": "Vec"}, {"name": "T"}], "o": {"name": "bool"}});
+        let search_type = SearchType::from_value(&value).unwrap();
+
+        assert_eq!(search_type.inputs, vec![Atom::from("vec"), Atom::from("t")]);
+        assert_eq!(search_type.output, vec![Atom::from("bool")]);
+    }
+
+    #[test]
+    fn test_null_output_is_not_a_wildcard() {
+        let search_type = SearchType::from_value(&json!({"i": [], "o": null})).unwrap();
+
+        assert!(search_type.output.is_empty());
+    }
+
+    #[test]
+    fn test_search_by_type_superset_and_implicit_self() {
+        let dedup = item(
+            TypeItem::Method(Atom::from("dedup")),
+            Some(TypeItem::Struct(Atom::from("Vec"))),
+            "std::vec",
+            "",
+            Some(
+                SearchType::from_value(&json!({"i": [], "o": null}))
+                    .unwrap()
+                    .with_implicit_self(),
+            ),
+            "std",
+        );
+        let contains = item(
+            TypeItem::Method(Atom::from("contains")),
+            Some(TypeItem::Struct(Atom::from("Vec"))),
+            "std::vec",
+            "",
+            Some(
+                SearchType::from_value(&json!({"i": [{"name": "T"}], "o": {"name": "bool"}}))
+                    .unwrap()
+                    .with_implicit_self(),
+            ),
+            "std",
+        );
+
+        let seeker = RustDoc::from_iter(vec![dedup, contains]).build();
+
+        let results = seeker.search_by_type(&["self"], Some("bool"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, TypeItem::Method(Atom::from("contains")));
+
+        let results = seeker.search_by_type(&["self"], None);
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_search_ranked_is_case_insensitive_and_ranks_exact_match_first() {
+        let vec_struct = item(
+            TypeItem::Struct(Atom::from("Vec")),
+            None,
+            "std::vec",
+            "",
+            None,
+            "std",
+        );
+        let vec_deque = item(
+            TypeItem::Struct(Atom::from("VecDeque")),
+            None,
+            "std::collections",
+            "",
+            None,
+            "std",
+        );
+
+        let seeker = RustDoc::from_iter(vec![vec_struct, vec_deque]).build();
+
+        // lowercase query must still match the capitalized stored names.
+        let results = seeker.search_ranked("vec");
+        assert_eq!(results.len(), 2);
+        // the exact name match ranks ahead of the mere prefix match.
+        assert_eq!(results[0].name, TypeItem::Struct(Atom::from("Vec")));
+    }
+
+    #[test]
+    fn test_search_desc_and_search_all() {
+        let dedup = item(
+            TypeItem::Method(Atom::from("dedup")),
+            Some(TypeItem::Struct(Atom::from("Vec"))),
+            "std::vec",
+            "Removes consecutive duplicate elements.",
+            None,
+            "std",
+        );
+        let push = item(
+            TypeItem::Method(Atom::from("push")),
+            Some(TypeItem::Struct(Atom::from("Vec"))),
+            "std::vec",
+            "Appends an element to the back of a collection.",
+            None,
+            "std",
+        );
+
+        let seeker = RustDoc::from_iter(vec![dedup, push]).build();
+
+        let results: Vec<_> = seeker.search_desc("duplicate").collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, TypeItem::Method(Atom::from("dedup")));
+
+        // the description half matches a word that isn't in either name.
+        let results = seeker.search_all("duplicate");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, TypeItem::Method(Atom::from("dedup")));
+
+        // the name-match half is case-insensitive, like `search_ranked`: an
+        // uppercase query still finds the lowercase stored name "push".
+        let results = seeker.search_all("PUSH");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, TypeItem::Method(Atom::from("push")));
+    }
+
+    #[test]
+    fn test_crates_and_search_in() {
+        let std_vec = item(
+            TypeItem::Struct(Atom::from("Vec")),
+            None,
+            "std::vec",
+            "",
+            None,
+            "std",
+        );
+        let tokio_vec = item(
+            TypeItem::Struct(Atom::from("Vec")),
+            None,
+            "tokio::vec",
+            "",
+            None,
+            "tokio",
+        );
+
+        let seeker = RustDoc::from_iter(vec![std_vec, tokio_vec]).build();
+
+        assert_eq!(seeker.crates().collect::<Vec<_>>(), vec!["std", "tokio"]);
+
+        let aut = Subsequence::new("Vec");
+        let results: Vec<_> = seeker.search_in("tokio", &aut).collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].path.as_ref(), "tokio::vec");
+    }
 }